@@ -1,5 +1,11 @@
 #![allow(non_upper_case_globals)]
 
+use rand_core::RngCore;
+use rand_core::SeedableRng;
+
+mod random64;
+pub use random64::Random64;
+
 // word size (in number of bits)
 const W: u32 = 32;
 // degree of recurrence
@@ -26,19 +32,60 @@ const F: u32 = 1812433253;
 // http://www.math.sci.hiroshima-u.ac.jp/~m-mat/MT/MT2002/CODES/mt19937ar.c
 const DEFAULT_SEED: u32 = 5489;
 
-struct Random {
+/// Inverts the tempering transform applied in [`Random::next`], recovering
+/// the raw `mt` word a given output was produced from.
+fn untemper(y: u32) -> u32 {
+    let y = undo_right_shift_xor(y, L, 0xFFFFFFFF);
+    let y = undo_left_shift_xor(y, T, C);
+    let y = undo_left_shift_xor(y, S, B);
+
+    undo_right_shift_xor(y, U, D)
+}
+
+/// Inverts `y ^= (y >> shift) & mask`, rebuilding the original value from
+/// the high bits down.
+fn undo_right_shift_xor(y: u32, shift: u32, mask: u32) -> u32 {
+    let mut x = y;
+    for _ in 0..(W + shift - 1) / shift {
+        x = y ^ ((x >> shift) & mask);
+    }
+
+    x
+}
+
+/// Inverts `y ^= (y << shift) & mask`, rebuilding the original value from
+/// the low bits up.
+fn undo_left_shift_xor(y: u32, shift: u32, mask: u32) -> u32 {
+    let mut x = y;
+    for _ in 0..(W + shift - 1) / shift {
+        x = y ^ ((x << shift) & mask);
+    }
+
+    x
+}
+
+pub struct Random {
     mt: [u32; N as usize],
     index: usize,
     lower_mask: u32,
     upper_mask: u32,
 }
 
+// http://www.math.sci.hiroshima-u.ac.jp/~m-mat/MT/MT2002/CODES/mt19937ar.c
+const INIT_KEY_SEED: u32 = 19650218;
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Random {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self::from_seed(DEFAULT_SEED)
     }
 
-    fn from_seed(seed: u32) -> Self {
+    pub fn from_seed(seed: u32) -> Self {
         let lower_mask = (1 << R) - 1;
         let mut random = Random {
             mt: [0u32; N as usize],
@@ -52,10 +99,164 @@ impl Random {
         random
     }
 
+    /// Seeds the generator from an arbitrary-length key, following the
+    /// reference `init_by_array` routine so outputs match `mt19937ar.c`
+    /// and CPython's `_random` module.
+    pub fn from_seed_array(key: &[u32]) -> Self {
+        let mut random = Self::from_seed(INIT_KEY_SEED);
+
+        let mut i = 1usize;
+        let mut j = 0usize;
+        for _ in 0..std::cmp::max(N as usize, key.len()) {
+            let previous = random.mt[i - 1] as u64;
+            let operation = (random.mt[i] as u64
+                ^ ((previous ^ (previous >> 30)) * 1664525))
+                + key[j] as u64
+                + j as u64;
+            random.mt[i] = operation as u32;
+
+            i += 1;
+            j += 1;
+            if i >= N as usize {
+                random.mt[0] = random.mt[N as usize - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+
+        for _ in 0..(N as usize - 1) {
+            let previous = random.mt[i - 1] as u64;
+            let operation = (random.mt[i] as u64
+                ^ ((previous ^ (previous >> 30)) * 1566083941))
+                .wrapping_sub(i as u64);
+            random.mt[i] = operation as u32;
+
+            i += 1;
+            if i >= N as usize {
+                random.mt[0] = random.mt[N as usize - 1];
+                i = 1;
+            }
+        }
+
+        random.mt[0] = 0x80000000;
+
+        random
+    }
+
+    /// Rebuilds a generator from a raw `mt` array and `index`, as recovered
+    /// from a snapshot or from observed outputs.
+    pub fn from_state(mt: [u32; N as usize], index: usize) -> Self {
+        let lower_mask = (1 << R) - 1;
+
+        Random {
+            mt,
+            index,
+            lower_mask,
+            upper_mask: !lower_mask,
+        }
+    }
+
+    /// Serializes the internal state as the 624 `u32` words of `mt`,
+    /// little-endian, followed by the 4-byte `index`, so it can be
+    /// checkpointed and resumed with [`Random::from_state_bytes`].
+    pub fn to_state_bytes(&self) -> [u8; 2500] {
+        let mut bytes = [0u8; 2500];
+
+        for (i, word) in self.mt.iter().enumerate() {
+            bytes[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        bytes[2496..2500].copy_from_slice(&(self.index as u32).to_le_bytes());
+
+        bytes
+    }
+
+    /// Reloads a generator from the state produced by
+    /// [`Random::to_state_bytes`].
+    pub fn from_state_bytes(bytes: &[u8; 2500]) -> Self {
+        let mut mt = [0u32; N as usize];
+        for (i, word) in mt.iter_mut().enumerate() {
+            let mut word_bytes = [0u8; 4];
+            word_bytes.copy_from_slice(&bytes[i * 4..i * 4 + 4]);
+            *word = u32::from_le_bytes(word_bytes);
+        }
+
+        let mut index_bytes = [0u8; 4];
+        index_bytes.copy_from_slice(&bytes[2496..2500]);
+        let index = u32::from_le_bytes(index_bytes) as usize;
+
+        Random::from_state(mt, index)
+    }
+
+    /// Reconstructs a generator from 624 consecutive `next()` outputs,
+    /// cloning a generator that has only been observed, by untempering
+    /// each output back into its raw `mt` word.
+    pub fn recover_from_outputs(outputs: &[u32; N as usize]) -> Self {
+        let mut mt = [0u32; N as usize];
+        for (i, &y) in outputs.iter().enumerate() {
+            mt[i] = untemper(y);
+        }
+
+        Random::from_state(mt, N as usize)
+    }
+
+    /// Returns a uniform integer in the inclusive range `[min, max]`, using
+    /// rejection sampling to avoid modulo bias.
+    pub fn get_int(&mut self, min: i32, max: i32) -> i32 {
+        assert!(min <= max, "min must not be greater than max");
+
+        let range = (max as i64 - min as i64) as u64 + 1;
+        if range > u32::MAX as u64 {
+            return self.next() as i32;
+        }
+        let range = range as u32;
+
+        let limit = u32::MAX - (u32::MAX % range);
+        loop {
+            let value = self.next();
+            if value < limit {
+                return min.wrapping_add((value % range) as i32);
+            }
+        }
+    }
+
+    /// Returns a uniformly distributed `f64` in `[min, max)`, built from the
+    /// high 53 bits of two draws for full double precision.
+    pub fn get_float(&mut self, min: f64, max: f64) -> f64 {
+        let high = (self.next() >> 5) as u64;
+        let low = (self.next() >> 6) as u64;
+        let value = (high * 67108864 + low) as f64 * (1.0 / 9007199254740992.0);
+
+        min + value * (max - min)
+    }
+
+    /// Returns a normally distributed `f64` with the given `mean` and
+    /// `std_dev`, via the Box–Muller transform.
+    pub fn get_gaussian(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = 1.0 - self.get_float(0.0, 1.0);
+        let u2 = 1.0 - self.get_float(0.0, 1.0);
+
+        mean + std_dev * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    /// Advances the stream by `n` outputs without materializing them,
+    /// twisting as needed, so callers can fast-forward to a known offset
+    /// in a reproducible sequence.
+    pub fn discard(&mut self, n: u64) {
+        for _ in 0..n {
+            if self.index >= N as usize {
+                self.twist();
+            }
+
+            self.index += 1;
+        }
+    }
+
     fn seed_mt(&mut self, seed: u32) {
         self.index = N as usize;
         self.mt[0] = seed;
-        for i in 1..(N as usize - 1) {
+        for i in 1..(N as usize) {
             let previous = self.mt[i - 1] as u64;
             let operation =
                 (F as u64 * (previous ^ (previous >> ((W - 2) as u64))))
@@ -82,7 +283,7 @@ impl Random {
     }
 
     fn twist(&mut self) {
-        for i in 0..(N as usize - 1) {
+        for i in 0..(N as usize) {
             let x = (self.mt[i] & self.upper_mask)
                 + (self.mt[(i + 1) % N as usize] & self.lower_mask);
             let mut x_a = x >> 1;
@@ -96,9 +297,48 @@ impl Random {
     }
 }
 
+impl RngCore for Random {
+    fn next_u32(&mut self) -> u32 {
+        self.next()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let high = self.next() as u64;
+        let low = self.next() as u64;
+
+        (high << 32) | low
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+impl SeedableRng for Random {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Random::from_seed(u32::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(seed: u64) -> Self {
+        Random::from_seed(seed as u32)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{DEFAULT_SEED, N, Random, R};
+    use rand_core::{RngCore, SeedableRng};
 
     const SOME_SEED: u32 = 32;
 
@@ -228,4 +468,205 @@ mod tests {
 
         assert_eq!(generator.index, 0);
     }
+
+    #[test]
+    fn from_seed_array_sets_initial_fields() {
+        let generator = Random::from_seed_array(&[0x123, 0x234, 0x345, 0x456]);
+
+        assert_eq!(generator.mt[0], 0x80000000);
+        assert_eq!(generator.index, N as usize);
+    }
+
+    #[test]
+    fn from_seed_array_generates_same_numbers_with_same_key() {
+        let mut a = Random::from_seed_array(&[SOME_SEED]);
+        let mut b = Random::from_seed_array(&[SOME_SEED]);
+
+        assert_eq!(a.next(), b.next());
+    }
+
+    #[test]
+    fn from_seed_array_generates_different_numbers_for_different_keys() {
+        let a_number = Random::from_seed_array(&[SOME_SEED]).next();
+        let a_different_number = Random::from_seed_array(&[11]).next();
+
+        assert_ne!(a_number, a_different_number);
+    }
+
+    #[test]
+    fn next_u32_matches_next() {
+        let mut a = some_generator();
+        let mut b = some_generator();
+
+        assert_eq!(a.next_u32(), b.next());
+    }
+
+    #[test]
+    fn next_u64_combines_two_draws() {
+        let mut generator = some_generator();
+        let mut reference = some_generator();
+
+        let expected = ((reference.next() as u64) << 32) | (reference.next() as u64);
+
+        assert_eq!(generator.next_u64(), expected);
+    }
+
+    #[test]
+    fn fill_bytes_fills_the_whole_buffer() {
+        let mut generator = some_generator();
+        let mut reference = some_generator();
+
+        let mut dest = [0u8; 10];
+        generator.fill_bytes(&mut dest);
+
+        let mut expected = [0u8; 10];
+        expected[0..4].copy_from_slice(&reference.next().to_le_bytes());
+        expected[4..8].copy_from_slice(&reference.next().to_le_bytes());
+        expected[8..10].copy_from_slice(&reference.next().to_le_bytes()[..2]);
+        assert_eq!(dest, expected);
+    }
+
+    #[test]
+    fn seedable_rng_from_seed_reconstructs_scalar_seed() {
+        let generator = <Random as SeedableRng>::from_seed(SOME_SEED.to_le_bytes());
+
+        assert_eq!(generator.mt[0], SOME_SEED);
+    }
+
+    #[test]
+    fn seedable_rng_seed_from_u64_truncates_to_u32() {
+        let generator = Random::seed_from_u64((1u64 << 32) | SOME_SEED as u64);
+
+        assert_eq!(generator.mt[0], SOME_SEED);
+    }
+
+    #[test]
+    fn to_state_bytes_round_trips_through_from_state_bytes() {
+        let mut generator = some_generator();
+        let _ = generator.next();
+
+        let bytes = generator.to_state_bytes();
+        let restored = Random::from_state_bytes(&bytes);
+
+        assert_eq!(restored.mt, generator.mt);
+        assert_eq!(restored.index, generator.index);
+    }
+
+    #[test]
+    fn to_state_bytes_round_trip_generates_same_future_numbers() {
+        let mut generator = some_generator();
+        let _ = generator.next();
+        let bytes = generator.to_state_bytes();
+
+        let mut restored = Random::from_state_bytes(&bytes);
+
+        assert_eq!(restored.next(), generator.next());
+    }
+
+    #[test]
+    fn from_state_sets_given_mt_and_index() {
+        let mt = [7u32; N as usize];
+
+        let generator = Random::from_state(mt, 3);
+
+        assert_eq!(generator.mt, mt);
+        assert_eq!(generator.index, 3);
+    }
+
+    #[test]
+    fn recover_from_outputs_clones_an_observed_generator() {
+        let mut observed = some_generator();
+
+        let mut outputs = [0u32; N as usize];
+        for output in outputs.iter_mut() {
+            *output = observed.next();
+        }
+
+        let mut recovered = Random::recover_from_outputs(&outputs);
+
+        assert_eq!(recovered.next(), observed.next());
+        assert_eq!(recovered.next(), observed.next());
+    }
+
+    #[test]
+    fn get_int_stays_within_inclusive_range() {
+        let mut generator = some_generator();
+
+        for _ in 0..1000 {
+            let value = generator.get_int(-5, 5);
+
+            assert!(value >= -5 && value <= 5);
+        }
+    }
+
+    #[test]
+    fn get_int_can_return_the_only_value_in_a_single_value_range() {
+        let mut generator = some_generator();
+
+        assert_eq!(generator.get_int(3, 3), 3);
+    }
+
+    #[test]
+    fn get_float_stays_within_range() {
+        let mut generator = some_generator();
+
+        for _ in 0..1000 {
+            let value = generator.get_float(-1.0, 1.0);
+
+            assert!(value >= -1.0 && value < 1.0);
+        }
+    }
+
+    #[test]
+    fn get_gaussian_centers_around_the_mean() {
+        let mut generator = some_generator();
+
+        let samples = 10_000;
+        let sum: f64 = (0..samples).map(|_| generator.get_gaussian(0.0, 1.0)).sum();
+        let average = sum / samples as f64;
+
+        assert!(average.abs() < 0.1);
+    }
+
+    #[test]
+    fn seed_mt_seeds_the_full_state() {
+        let generator = some_generator();
+
+        assert_ne!(generator.mt[N as usize - 1], 0);
+    }
+
+    #[test]
+    fn twist_twists_the_full_state() {
+        let mut generator = some_generator();
+
+        generator.twist();
+
+        assert_ne!(generator.mt[N as usize - 1], 0);
+    }
+
+    #[test]
+    fn discard_skips_the_given_number_of_outputs() {
+        let mut discarded = some_generator();
+        discarded.discard(3);
+
+        let mut stepped = some_generator();
+        stepped.next();
+        stepped.next();
+        stepped.next();
+
+        assert_eq!(discarded.next(), stepped.next());
+    }
+
+    #[test]
+    fn discard_twists_when_crossing_the_state_boundary() {
+        let mut discarded = some_generator();
+        discarded.discard(N as u64 + 1);
+
+        let mut stepped = some_generator();
+        for _ in 0..(N as usize + 1) {
+            stepped.next();
+        }
+
+        assert_eq!(discarded.next(), stepped.next());
+    }
 }
@@ -0,0 +1,274 @@
+#![allow(non_upper_case_globals)]
+
+use rand_core::RngCore;
+
+// word size (in number of bits)
+const W: u32 = 64;
+// degree of recurrence
+const N: u32 = 312;
+// middle word, an offset used in the recurrence relation defining the series x, 1 ≤ m < n
+const M: u32 = 156;
+// separation point of one word, or the number of bits of the lower bitmask, 0 ≤ r ≤ w - 1
+const R: u32 = 31;
+// coefficients of the rational normal form twist matrix
+const A: u64 = 0xB5026F5AA96619E9;
+// b, c: TGFSR(R) tempering bitmasks
+const B: u64 = 0x71D67FFFEDA60000;
+const C: u64 = 0xFFF7EEE000000000;
+// s, t: TGFSR(R) tempering bit shifts
+const S: u32 = 17;
+const T: u32 = 37;
+// u, d, l: additional Mersenne Twister tempering bit shifts/masks
+const U: u32 = 29;
+const D: u64 = 0x5555555555555555;
+const L: u32 = 43;
+
+const F: u64 = 6364136223846793005;
+
+// http://www.math.sci.hiroshima-u.ac.jp/~m-mat/MT/VERSIONS/C-LANG/mt19937-64.c
+const DEFAULT_SEED: u64 = 5489;
+
+/// A MT19937-64 generator, the 64-bit variant of the Mersenne Twister,
+/// producing a `u64` output stream.
+pub struct Random64 {
+    mt: [u64; N as usize],
+    index: usize,
+    lower_mask: u64,
+    upper_mask: u64,
+}
+
+impl Default for Random64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Random64 {
+    pub fn new() -> Self {
+        Self::from_seed(DEFAULT_SEED)
+    }
+
+    pub fn from_seed(seed: u64) -> Self {
+        let lower_mask = (1 << R) - 1;
+        let mut random = Random64 {
+            mt: [0u64; N as usize],
+            index: N as usize + 1,
+            lower_mask,
+            upper_mask: !lower_mask,
+        };
+
+        random.seed_mt(seed);
+
+        random
+    }
+
+    fn seed_mt(&mut self, seed: u64) {
+        self.index = N as usize;
+        self.mt[0] = seed;
+        for i in 1..(N as usize) {
+            let previous = self.mt[i - 1];
+            let operation =
+                F.wrapping_mul(previous ^ (previous >> (W - 2)))
+                    .wrapping_add(i as u64);
+
+            self.mt[i] = operation;
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        if self.index >= N as usize {
+            self.twist();
+        }
+
+        let mut y = self.mt[self.index];
+        y ^= (y >> U) & D;
+        y ^= (y << S) & B;
+        y ^= (y << T) & C;
+        y ^= y >> L;
+
+        self.index += 1;
+
+        y
+    }
+
+    fn twist(&mut self) {
+        for i in 0..(N as usize) {
+            let x = (self.mt[i] & self.upper_mask)
+                + (self.mt[(i + 1) % N as usize] & self.lower_mask);
+            let mut x_a = x >> 1;
+            if x % 2 != 0 {
+                x_a ^= A;
+            }
+            self.mt[i] = self.mt[(i + M as usize) % N as usize] ^ x_a;
+        }
+
+        self.index = 0;
+    }
+}
+
+impl RngCore for Random64 {
+    fn next_u32(&mut self) -> u32 {
+        self.next() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.next()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random64::{DEFAULT_SEED, N, Random64, R};
+    use rand_core::RngCore;
+
+    const SOME_SEED: u64 = 32;
+
+    fn some_generator() -> Random64 {
+        Random64::from_seed(SOME_SEED)
+    }
+
+    #[test]
+    fn new_initializes_with_default_seed() {
+        let generator = Random64::new();
+
+        let seed = generator.mt[0];
+
+        assert_eq!(seed, DEFAULT_SEED);
+    }
+
+    #[test]
+    fn from_seed_sets_initial_fields() {
+        let generator = Random64::from_seed(SOME_SEED);
+
+        assert_ne!(generator.mt[1], 0);
+        assert_eq!(generator.index, N as usize);
+        assert_eq!(generator.lower_mask, (1 << R) - 1);
+        assert_eq!(!generator.lower_mask, generator.upper_mask);
+    }
+
+    #[test]
+    fn next_twists_when_needed() {
+        let mut generator = some_generator();
+
+        let index_after_twist = 0;
+        let index_after_number_extract = 1;
+        let index_with_no_twist = index_after_twist + index_after_number_extract;
+        assert_ne!(generator.index, index_with_no_twist);
+
+        let _ = generator.next();
+
+        let index_after_first_twist = index_after_twist + index_after_number_extract;
+        assert_eq!(generator.index, index_after_first_twist);
+
+        let _ = generator.next();
+
+        let index_after_second_twist = index_after_first_twist + index_after_number_extract;
+        assert_eq!(generator.index, index_after_second_twist);
+    }
+
+    /// would panic if `twist()` was not called
+    #[test]
+    fn next_allows_generation_of_more_than_n_numbers() {
+        let mut generator = some_generator();
+        for _ in 0..(N as usize) * 2 {
+            generator.next();
+        }
+    }
+
+    #[test]
+    fn next_generates_same_number_with_same_seed() {
+        let a_number = some_generator().next();
+
+        let a_same_number = some_generator().next();
+
+        assert_eq!(a_number, a_same_number);
+    }
+
+    #[test]
+    fn next_generates_different_numbers_for_different_seeds() {
+        let a_number = some_generator().next();
+
+        let a_different_number = Random64::from_seed(11).next();
+
+        assert_ne!(a_number, a_different_number);
+    }
+
+    #[test]
+    fn next_generates_different_numbers_on_sequential_calls() {
+        let mut generator = Random64::new();
+        let a_number = generator.next();
+
+        let a_different_number = generator.next();
+
+        assert_ne!(a_number, a_different_number);
+    }
+
+    #[test]
+    fn twist_resets_index() {
+        let mut generator = Random64::new();
+
+        generator.twist();
+
+        assert_eq!(generator.index, 0);
+    }
+
+    #[test]
+    fn seed_mt_seeds_the_full_state() {
+        let generator = some_generator();
+
+        assert_ne!(generator.mt[N as usize - 1], 0);
+    }
+
+    #[test]
+    fn twist_twists_the_full_state() {
+        let mut generator = some_generator();
+
+        generator.twist();
+
+        assert_ne!(generator.mt[N as usize - 1], 0);
+    }
+
+    #[test]
+    fn next_u64_matches_next() {
+        let mut a = some_generator();
+        let mut b = some_generator();
+
+        assert_eq!(a.next_u64(), b.next());
+    }
+
+    #[test]
+    fn next_u32_truncates_next() {
+        let mut generator = some_generator();
+        let mut reference = some_generator();
+
+        assert_eq!(generator.next_u32(), reference.next() as u32);
+    }
+
+    #[test]
+    fn fill_bytes_fills_the_whole_buffer() {
+        let mut generator = some_generator();
+        let mut reference = some_generator();
+
+        let mut dest = [0u8; 20];
+        generator.fill_bytes(&mut dest);
+
+        let mut expected = [0u8; 20];
+        expected[0..8].copy_from_slice(&reference.next().to_le_bytes());
+        expected[8..16].copy_from_slice(&reference.next().to_le_bytes());
+        expected[16..20].copy_from_slice(&reference.next().to_le_bytes()[..4]);
+        assert_eq!(dest, expected);
+    }
+}